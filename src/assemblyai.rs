@@ -0,0 +1,148 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use futures::StreamExt;
+use indicatif::{ProgressBar, ProgressStyle};
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Body, Client,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio_util::io::ReaderStream;
+
+use crate::provider::{SpeechProvider, TranscriptStatus};
+use crate::transcript::{Status, TranscriptResponse};
+
+pub const DEFAULT_BASE_URL: &str = "https://api.assemblyai.com/v2/transcript";
+const UPLOAD_URL: &str = "https://api.assemblyai.com/v2/upload";
+
+#[derive(Debug, Deserialize)]
+struct UploadResponse {
+    upload_url: String,
+}
+
+/// A `SpeechProvider` backed by AssemblyAI's transcription API.
+pub struct AssemblyAiProvider {
+    client: Client,
+    headers: HeaderMap,
+    api_url: String,
+    token: String,
+}
+
+impl AssemblyAiProvider {
+    /// Creates a new `AssemblyAiProvider` instance.
+    pub fn new(token: &str, api_url: String) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            HeaderValue::from_str(token).expect("api_token as str"),
+        );
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+
+        Self {
+            client: Client::new(),
+            headers,
+            api_url,
+            token: token.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl SpeechProvider for AssemblyAiProvider {
+    async fn submit(&self, audio_url: &str) -> Result<String> {
+        let data = json!({
+            "audio_url": audio_url,
+            "iab_categories": true,
+            "entity_detection": true
+        });
+        let response = self
+            .client
+            .post(&self.api_url)
+            .headers(self.headers.clone())
+            .json(&data)
+            .send()
+            .await
+            .context("err posting to transcript endpoint")?;
+
+        let parsed_json = response.json::<Value>().await.map_err(|e| {
+            eprintln!("ERROR: could not read body of response: {}", e);
+            e
+        })?;
+
+        parsed_json
+            .get("id")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .ok_or_else(|| anyhow!("'id' key not found in response body: {:?}", parsed_json))
+    }
+
+    async fn poll(&self, id: &str) -> Result<TranscriptStatus> {
+        let polling_endpoint = format!("{}/{}", self.api_url, id);
+        let transcript_res = self
+            .client
+            .get(&polling_endpoint)
+            .headers(self.headers.clone())
+            .send()
+            .await
+            .context("err get: transcript response")?;
+
+        let transcript_data: TranscriptResponse = transcript_res
+            .json()
+            .await
+            .context("could not read body of poll request")?;
+
+        Ok(match transcript_data.status {
+            Status::Queued => TranscriptStatus::Queued,
+            Status::Processing => TranscriptStatus::Processing,
+            Status::Completed => TranscriptStatus::Completed(transcript_data),
+            Status::Error => {
+                TranscriptStatus::Error(transcript_data.error.unwrap_or_default())
+            }
+        })
+    }
+
+    async fn upload(&self, path: &Path) -> Result<String> {
+        let file = tokio::fs::File::open(path)
+            .await
+            .with_context(|| format!("failed to open audio file {:#?}", path))?;
+        let file_size = file.metadata().await?.len();
+
+        let progress = ProgressBar::new(file_size);
+        progress.set_style(
+            ProgressStyle::with_template(
+                "{spinner:.green} [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})",
+            )
+            .unwrap_or_else(|_| ProgressStyle::default_bar()),
+        );
+
+        let uploaded = progress.clone();
+        let stream = ReaderStream::new(file).inspect(move |chunk| {
+            if let Ok(chunk) = chunk {
+                uploaded.inc(chunk.len() as u64);
+            }
+        });
+
+        let response = self
+            .client
+            .post(UPLOAD_URL)
+            .header(reqwest::header::AUTHORIZATION, &self.token)
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(Body::wrap_stream(stream))
+            .send()
+            .await
+            .context("err posting to upload endpoint")?;
+        progress.finish_and_clear();
+
+        let upload: UploadResponse = response
+            .json()
+            .await
+            .context("could not read body of upload response")?;
+        Ok(upload.upload_url)
+    }
+}
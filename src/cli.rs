@@ -1,10 +1,22 @@
 use clap::Parser;
 
+use crate::provider::Provider;
+
 #[derive(Parser)]
 #[command(bin_name = "assembly-cli")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: AssemblyCLI,
+    /// Loads a named profile from assembly-cli.toml on top of the default profile.
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+}
+
+#[derive(clap::Subcommand)]
 pub enum AssemblyCLI {
     Transcribe(TranscriberArgs),
     Question(QuestionArgs),
+    Stream(StreamArgs),
 }
 
 #[derive(Debug, clap::Args)]
@@ -15,10 +27,22 @@ pub enum AssemblyCLI {
     long_about = "Sends an audio transcription request to a specified URL and retrieves the transcription ID from the response."
 )]
 pub struct TranscriberArgs {
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with = "audio_file")]
     pub audio_url: Option<String>,
+    /// Uploads a local audio file instead of requiring an already-hosted --audio-url.
+    #[arg(long)]
+    pub audio_file: Option<std::path::PathBuf>,
     #[arg(short, long)]
-    pub transcript_id: Option<String>,
+    pub transcript_id: Vec<String>,
+    /// Which ASR backend to submit and poll transcription jobs against.
+    #[arg(long, value_enum, default_value_t = Provider::AssemblyAi)]
+    pub provider: Provider,
+    /// Overrides the provider's default API base URL, e.g. for a proxy or self-hosted endpoint.
+    #[arg(long)]
+    pub base_url: Option<String>,
+    /// Overrides the configured poll interval, in seconds, between transcription status checks.
+    #[arg(long)]
+    pub poll_interval_secs: Option<u64>,
 }
 
 #[derive(Debug, clap::Args)]
@@ -32,4 +56,25 @@ pub struct QuestionArgs {
     pub questions_file_path: std::path::PathBuf,
     #[arg(short, long)]
     pub transcript_id: Vec<String>,
+    /// Streams answer tokens as they arrive instead of waiting for the full batch.
+    #[arg(long)]
+    pub stream: bool,
+    /// Overrides the configured LeMUR final_model used to answer questions.
+    #[arg(long)]
+    pub final_model: Option<String>,
+}
+
+#[derive(Debug, clap::Args)]
+#[command(
+    name = "stream",
+    about,
+    long_about = "Streams local audio to AssemblyAI's real-time transcription endpoint and prints partial and final transcripts as they arrive."
+)]
+pub struct StreamArgs {
+    /// Streams a local audio file instead of the microphone.
+    #[arg(short, long, conflicts_with = "mic")]
+    pub audio_file: Option<std::path::PathBuf>,
+    /// Streams from the default microphone input device instead of a file.
+    #[arg(long)]
+    pub mic: bool,
 }
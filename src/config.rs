@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::{env, fs, path::PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use tokio::time::Duration;
+
+const CONFIG_FILE_NAME: &str = "assembly-cli.toml";
+const DEFAULT_FINAL_MODEL: &str = "basic";
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 10;
+
+/// Fields loadable from `assembly-cli.toml`, either at the top level (the
+/// default profile) or nested under `[profile.<name>]`.
+#[derive(Debug, Default, Deserialize, Clone)]
+struct Profile {
+    token: Option<String>,
+    transcript_url: Option<String>,
+    question_url: Option<String>,
+    deepgram_url: Option<String>,
+    stream_url: Option<String>,
+    final_model: Option<String>,
+    poll_interval_secs: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawConfig {
+    #[serde(flatten)]
+    default: Profile,
+    #[serde(default)]
+    profile: HashMap<String, Profile>,
+}
+
+/// Centralized CLI configuration, loaded from `assembly-cli.toml` and then
+/// overridden by environment variables. CLI flags are applied on top of
+/// this by the individual subcommands, so the precedence is: file < env < flag.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub token: String,
+    pub transcript_url: Option<String>,
+    pub question_url: Option<String>,
+    pub deepgram_url: Option<String>,
+    pub stream_url: Option<String>,
+    pub final_model: String,
+    pub poll_interval: Duration,
+}
+
+impl Config {
+    /// Loads configuration from `assembly-cli.toml` (searched in the current
+    /// directory, then `$XDG_CONFIG_HOME/assembly-cli/`), applies the named
+    /// `profile` on top of the default profile if given, then lets
+    /// `API_TOKEN`/`TRANSCRIPT_URL`/`QUESTION_URL`/`DEEPGRAM_URL`/`STREAM_URL`/
+    /// `FINAL_MODEL`/`POLL_INTERVAL_SECS` env vars override the file. Fails
+    /// fast if no token is available from either source. The transcription
+    /// base URLs, `final_model` and the poll interval can be overridden again
+    /// by a CLI flag on the individual subcommands, so for those the full
+    /// precedence is: file < env < flag. The token, `question_url` and
+    /// `stream_url` have no CLI flag.
+    pub fn load(profile: Option<&str>) -> Result<Self> {
+        let raw = Self::read_raw_config()?;
+        let mut resolved = raw.default;
+
+        if let Some(name) = profile {
+            let profile = raw
+                .profile
+                .get(name)
+                .ok_or_else(|| anyhow!("profile '{}' not found in {}", name, CONFIG_FILE_NAME))?
+                .clone();
+            resolved = merge(resolved, profile);
+        }
+
+        let token = env::var("API_TOKEN")
+            .ok()
+            .or(resolved.token)
+            .ok_or_else(|| anyhow!("API_TOKEN not set."))?;
+
+        let poll_interval_secs = env::var("POLL_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .or(resolved.poll_interval_secs)
+            .unwrap_or(DEFAULT_POLL_INTERVAL_SECS);
+
+        Ok(Self {
+            token,
+            transcript_url: env::var("TRANSCRIPT_URL").ok().or(resolved.transcript_url),
+            question_url: env::var("QUESTION_URL").ok().or(resolved.question_url),
+            deepgram_url: env::var("DEEPGRAM_URL").ok().or(resolved.deepgram_url),
+            stream_url: env::var("STREAM_URL").ok().or(resolved.stream_url),
+            final_model: env::var("FINAL_MODEL")
+                .ok()
+                .or(resolved.final_model)
+                .unwrap_or_else(|| DEFAULT_FINAL_MODEL.to_string()),
+            poll_interval: Duration::from_secs(poll_interval_secs),
+        })
+    }
+
+    fn read_raw_config() -> Result<RawConfig> {
+        match Self::find_config_file()? {
+            Some(path) => {
+                let contents = fs::read_to_string(&path)
+                    .with_context(|| format!("failed to read {:#?}", path))?;
+                toml::from_str(&contents)
+                    .with_context(|| format!("failed to parse {:#?}", path))
+            }
+            None => Ok(RawConfig::default()),
+        }
+    }
+
+    fn find_config_file() -> Result<Option<PathBuf>> {
+        let cwd_path = env::current_dir()?.join(CONFIG_FILE_NAME);
+        if cwd_path.is_file() {
+            return Ok(Some(cwd_path));
+        }
+
+        if let Some(xdg_config_home) = env::var_os("XDG_CONFIG_HOME") {
+            let xdg_path = PathBuf::from(xdg_config_home)
+                .join("assembly-cli")
+                .join(CONFIG_FILE_NAME);
+            if xdg_path.is_file() {
+                return Ok(Some(xdg_path));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Overlays `override_profile`'s present fields on top of `base`.
+fn merge(base: Profile, override_profile: Profile) -> Profile {
+    Profile {
+        token: override_profile.token.or(base.token),
+        transcript_url: override_profile.transcript_url.or(base.transcript_url),
+        question_url: override_profile.question_url.or(base.question_url),
+        deepgram_url: override_profile.deepgram_url.or(base.deepgram_url),
+        stream_url: override_profile.stream_url.or(base.stream_url),
+        final_model: override_profile.final_model.or(base.final_model),
+        poll_interval_secs: override_profile.poll_interval_secs.or(base.poll_interval_secs),
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Client,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::provider::{SpeechProvider, TranscriptStatus};
+use crate::transcript::{Entity, Status, TranscriptResponse, Word};
+
+pub const DEFAULT_BASE_URL: &str = "https://api.deepgram.com/v1/listen";
+
+#[derive(Debug, Deserialize)]
+struct DeepgramResponse {
+    results: DeepgramResults,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepgramResults {
+    channels: Vec<DeepgramChannel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepgramChannel {
+    alternatives: Vec<DeepgramAlternative>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepgramAlternative {
+    transcript: String,
+    #[serde(default)]
+    words: Vec<DeepgramWord>,
+    #[serde(default)]
+    entities: Vec<DeepgramEntity>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepgramWord {
+    word: String,
+    start: f64,
+    end: f64,
+    confidence: f64,
+}
+
+/// An entity detected by Deepgram's `detect_entities` option. `start_word`
+/// and `end_word` are indexes into the alternative's `words`, not timestamps.
+#[derive(Debug, Deserialize)]
+struct DeepgramEntity {
+    label: String,
+    value: String,
+    start_word: usize,
+    end_word: usize,
+}
+
+/// A `SpeechProvider` backed by Deepgram's pre-recorded transcription API.
+/// Deepgram's `/listen` endpoint transcribes synchronously, so `submit`
+/// completes the job inline and caches the normalized result under a
+/// generated ID for `poll` to return.
+pub struct DeepgramProvider {
+    client: Client,
+    headers: HeaderMap,
+    api_url: String,
+    completed: Mutex<HashMap<String, TranscriptResponse>>,
+}
+
+impl DeepgramProvider {
+    /// Creates a new `DeepgramProvider` instance.
+    pub fn new(token: &str, api_url: String) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            HeaderValue::from_str(&format!("Token {}", token)).expect("api_token as str"),
+        );
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+
+        Self {
+            client: Client::new(),
+            headers,
+            api_url,
+            completed: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl SpeechProvider for DeepgramProvider {
+    async fn submit(&self, audio_url: &str) -> Result<String> {
+        let data = json!({ "url": audio_url });
+        let response = self
+            .client
+            .post(format!("{}?detect_entities=true", self.api_url))
+            .headers(self.headers.clone())
+            .json(&data)
+            .send()
+            .await
+            .context("err posting to deepgram listen endpoint")?;
+
+        let parsed: DeepgramResponse = response
+            .json()
+            .await
+            .context("could not read body of deepgram response")?;
+
+        let alternative = parsed
+            .results
+            .channels
+            .into_iter()
+            .next()
+            .and_then(|c| c.alternatives.into_iter().next())
+            .context("deepgram response contained no transcription alternatives")?;
+
+        let mut hasher = DefaultHasher::new();
+        audio_url.hash(&mut hasher);
+        alternative.transcript.hash(&mut hasher);
+        let id = format!("deepgram-{:x}", hasher.finish());
+
+        // `start_word`/`end_word` index into `words`, so resolve them to
+        // millisecond timestamps before `words` is consumed below.
+        let entities = alternative
+            .entities
+            .iter()
+            .map(|e| Entity {
+                entity_type: e.label.clone(),
+                text: e.value.clone(),
+                start: alternative
+                    .words
+                    .get(e.start_word)
+                    .map(|w| (w.start * 1000.0) as u64)
+                    .unwrap_or(0),
+                end: alternative
+                    .words
+                    .get(e.end_word)
+                    .map(|w| (w.end * 1000.0) as u64)
+                    .unwrap_or(0),
+            })
+            .collect::<Vec<_>>();
+
+        let transcript = TranscriptResponse {
+            id: id.clone(),
+            status: Status::Completed,
+            text: alternative.transcript,
+            words: alternative
+                .words
+                .into_iter()
+                .map(|w| Word {
+                    text: w.word,
+                    start: (w.start * 1000.0) as u64,
+                    end: (w.end * 1000.0) as u64,
+                    confidence: w.confidence,
+                    speaker: None,
+                })
+                .collect(),
+            iab_categories_result: None,
+            entities: (!entities.is_empty()).then_some(entities),
+            error: None,
+        };
+
+        self.completed
+            .lock()
+            .expect("completed transcripts lock poisoned")
+            .insert(id.clone(), transcript);
+
+        Ok(id)
+    }
+
+    async fn poll(&self, id: &str) -> Result<TranscriptStatus> {
+        let transcript = self
+            .completed
+            .lock()
+            .expect("completed transcripts lock poisoned")
+            .remove(id);
+
+        Ok(match transcript {
+            Some(transcript) => TranscriptStatus::Completed(transcript),
+            None => TranscriptStatus::Error(format!("unknown transcript ID: {}", id)),
+        })
+    }
+}
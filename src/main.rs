@@ -1,21 +1,27 @@
-use std::env;
-
-use anyhow::anyhow;
 use anyhow::Result;
 use clap::Parser;
-use cli::AssemblyCLI;
+use cli::{AssemblyCLI, Cli};
 use dotenv::dotenv;
 
+mod assemblyai;
 mod cli;
+mod config;
+mod deepgram;
+mod provider;
 mod question_answer;
+mod stream;
 mod transcribe;
+mod transcript;
 
-fn main() -> Result<()> {
+#[tokio::main]
+async fn main() -> Result<()> {
     dotenv()?;
-    let api_token = env::var("API_TOKEN").map_err(|_| anyhow!("API_TOKEN not set."))?;
+    let cli = Cli::parse();
+    let config = config::Config::load(cli.profile.as_deref())?;
 
-    match AssemblyCLI::parse() {
-        AssemblyCLI::Transcribe(args) => transcribe::run(&api_token, args),
-        AssemblyCLI::Question(args) => question_answer::run(&api_token, args),
+    match cli.command {
+        AssemblyCLI::Transcribe(args) => transcribe::run(&config, args).await,
+        AssemblyCLI::Question(args) => question_answer::run(&config, args).await,
+        AssemblyCLI::Stream(args) => stream::run(&config, args).await,
     }
 }
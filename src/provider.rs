@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+
+use crate::transcript::TranscriptResponse;
+
+/// Which ASR backend to submit and poll transcription jobs against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Provider {
+    AssemblyAi,
+    Deepgram,
+}
+
+impl std::fmt::Display for Provider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Provider::AssemblyAi => write!(f, "assembly-ai"),
+            Provider::Deepgram => write!(f, "deepgram"),
+        }
+    }
+}
+
+/// The normalized state of a transcription job, regardless of which
+/// `SpeechProvider` produced it.
+#[derive(Debug)]
+pub enum TranscriptStatus {
+    Queued,
+    Processing,
+    Completed(TranscriptResponse),
+    Error(String),
+}
+
+/// A speech-to-text backend capable of submitting audio for transcription
+/// and polling for its result. Implementors normalize their own response
+/// shape into `TranscriptStatus` so the rest of the CLI never branches on
+/// which provider is in use.
+#[async_trait]
+pub trait SpeechProvider {
+    /// Submits `audio_url` for transcription and returns the job ID.
+    async fn submit(&self, audio_url: &str) -> Result<String>;
+    /// Polls the job identified by `id` and returns its current status.
+    async fn poll(&self, id: &str) -> Result<TranscriptStatus>;
+    /// Uploads a local audio file and returns a URL `submit` can transcribe.
+    /// Providers that only accept already-hosted URLs can leave this unimplemented.
+    async fn upload(&self, _path: &Path) -> Result<String> {
+        Err(anyhow!("this provider does not support uploading local audio files"))
+    }
+}
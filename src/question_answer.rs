@@ -1,16 +1,31 @@
+use std::collections::HashSet;
+use std::io::Write;
 use std::path::Path;
-use std::{env, fs, io};
+use std::{fs, io};
 
 use crate::cli::QuestionArgs;
-use anyhow::anyhow;
-use anyhow::Result;
+use crate::config::Config;
+use anyhow::{anyhow, Context, Result};
+use eventsource_stream::Eventsource;
+use futures::StreamExt;
 use reqwest::{
-    blocking::Client,
     header::{HeaderMap, HeaderValue},
+    Client,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
+const DEFAULT_QUESTION_URL: &str = "https://api.assemblyai.com/lemur/v3/generate/question-answer";
+
+/// A single partial-answer delta from the streamed SSE variant of the Q&A endpoint.
+#[derive(Debug, Deserialize)]
+struct AnswerDelta {
+    question: String,
+    answer_delta: String,
+    #[serde(default)]
+    finished: bool,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Question {
     question: String,
@@ -27,14 +42,15 @@ pub struct Answer {
 #[derive(Debug)]
 /// A client for interacting with the AssemblyAI question and answer service.
 pub struct QuestionAnswer<'a> {
-    client: reqwest::blocking::Client,
+    client: Client,
     headers: HeaderMap,
     api_url: &'a str,
+    final_model: &'a str,
 }
 
 impl<'a> QuestionAnswer<'a> {
     /// Creates ja new `QuestionAnswer` instance.
-    pub fn new(client: reqwest::blocking::Client, token: &str, api_url: &'a str) -> Self {
+    pub fn new(client: Client, token: &str, api_url: &'a str, final_model: &'a str) -> Self {
         let mut headers = HeaderMap::new();
         headers.insert(
             reqwest::header::AUTHORIZATION,
@@ -49,15 +65,16 @@ impl<'a> QuestionAnswer<'a> {
             client,
             headers,
             api_url,
+            final_model,
         }
     }
 
     /// Sends a series of questions to and retrieves the answers.
-    pub fn ask(&self, transcript_ids: Vec<String>, questions: Vec<Question>) -> Result<()> {
+    pub async fn ask(&self, transcript_ids: Vec<String>, questions: Vec<Question>) -> Result<()> {
         let data = json!({
             "transcript_ids": transcript_ids,
             "questions": questions,
-            "final_model": "basic",
+            "final_model": self.final_model,
         });
 
         let response = self
@@ -65,9 +82,10 @@ impl<'a> QuestionAnswer<'a> {
             .post(self.api_url)
             .headers(self.headers.clone())
             .json(&data)
-            .send()?;
+            .send()
+            .await?;
 
-        let parsed_json = response.json::<Value>().map_err(|e| {
+        let parsed_json = response.json::<Value>().await.map_err(|e| {
             eprintln!("Error: could not read body of response: {}", e);
             e
         })?;
@@ -91,15 +109,93 @@ impl<'a> QuestionAnswer<'a> {
         }
         Ok(())
     }
+
+    /// Sends a series of questions to the SSE variant of the Q&A endpoint and
+    /// prints each answer's tokens as they arrive, flushing a final newline
+    /// once that question's stream finishes.
+    pub async fn ask_streaming(
+        &self,
+        transcript_ids: Vec<String>,
+        questions: Vec<Question>,
+    ) -> Result<()> {
+        let data = json!({
+            "transcript_ids": transcript_ids,
+            "questions": questions,
+            "final_model": self.final_model,
+            "stream": true,
+        });
+
+        let response = self
+            .client
+            .post(self.api_url)
+            .headers(self.headers.clone())
+            .json(&data)
+            .send()
+            .await?
+            .error_for_status()
+            .context("question-answer stream request failed")?;
+
+        let mut events = response.bytes_stream().eventsource();
+        // Deltas for different questions can interleave within a single batched
+        // call: print each question's header once (HashSet) and fall back to a
+        // "continued" marker if a later delta resumes a question that isn't the
+        // one most recently printed to (last_printed).
+        let mut headers_printed: HashSet<String> = HashSet::new();
+        let mut last_printed: Option<String> = None;
+
+        while let Some(event) = events.next().await {
+            let event = event.context("err reading SSE frame")?;
+            if event.data.is_empty() {
+                continue;
+            }
+            let delta: AnswerDelta = serde_json::from_str(&event.data)
+                .with_context(|| format!("could not parse SSE delta: {}", event.data))?;
+
+            if headers_printed.insert(delta.question.clone()) {
+                if last_printed.is_some() {
+                    println!();
+                }
+                println!("Question: {}", delta.question);
+                print!("Answer: ");
+            } else if last_printed.as_deref() != Some(delta.question.as_str()) {
+                println!();
+                print!("[{}, continued] ", delta.question);
+            }
+
+            print!("{}", delta.answer_delta);
+            io::stdout().flush()?;
+            last_printed = Some(delta.question.clone());
+
+            if delta.finished {
+                println!();
+                last_printed = None;
+            }
+        }
+
+        if last_printed.is_some() {
+            println!();
+        }
+        Ok(())
+    }
 }
 
-pub fn run(token: &str, args: QuestionArgs) -> Result<()> {
-    let api_url = env::var("QUESTION_URL").map_err(|_| anyhow!("QUESTION_URL not set."))?;
+pub async fn run(config: &Config, args: QuestionArgs) -> Result<()> {
+    let api_url = config
+        .question_url
+        .clone()
+        .unwrap_or_else(|| DEFAULT_QUESTION_URL.to_string());
+
+    let final_model = args.final_model.as_deref().unwrap_or(&config.final_model);
 
     let client = Client::new();
-    let qa = QuestionAnswer::new(client, token, &api_url);
+    let qa = QuestionAnswer::new(client, &config.token, &api_url, final_model);
     let questions = read_questions_from_file(args.questions_file_path)?;
-    qa.ask(args.transcript_id, questions)
+
+    if args.stream {
+        qa.ask_streaming(args.transcript_id, questions).await
+    } else {
+        qa.ask(args.transcript_id, questions).await
+    }
 }
 
 /// Reads a series of questions from a JSON file.
@@ -0,0 +1,272 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::cli::StreamArgs;
+use crate::config::Config;
+
+const DEFAULT_STREAM_URL: &str = "wss://api.assemblyai.com/v2/realtime/ws";
+const SAMPLE_RATE: u32 = 16_000;
+/// Bytes per 100ms of 16kHz mono 16-bit PCM audio.
+const CHUNK_BYTES: usize = (SAMPLE_RATE as usize / 10) * 2;
+
+/// Where to source PCM audio from for a streaming session.
+pub enum AudioSource {
+    File(std::path::PathBuf),
+    Microphone,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "message_type")]
+enum StreamEvent {
+    SessionBegins {
+        session_id: String,
+    },
+    PartialTranscript {
+        text: String,
+    },
+    FinalTranscript {
+        text: String,
+        audio_start: u64,
+        audio_end: u64,
+    },
+    SessionTerminated,
+    Error {
+        error: String,
+    },
+}
+
+#[derive(Debug, Serialize)]
+struct AudioFrame<'a> {
+    audio_data: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct TerminateSession {
+    terminate_session: bool,
+}
+
+/// A client for AssemblyAI's real-time transcription WebSocket endpoint.
+pub struct Streamer<'a> {
+    api_url: &'a str,
+    token: &'a str,
+}
+
+impl<'a> Streamer<'a> {
+    /// Creates a new `Streamer` instance.
+    pub fn new(token: &'a str, api_url: &'a str) -> Self {
+        Self { api_url, token }
+    }
+
+    /// Opens the real-time WebSocket connection, pushes PCM audio frames read
+    /// from `source`, and prints partial and final transcripts as they arrive.
+    pub async fn stream(&self, source: AudioSource) -> Result<Vec<String>> {
+        let url = format!(
+            "{}?sample_rate={}&token={}",
+            self.api_url, SAMPLE_RATE, self.token
+        );
+        let (ws_stream, _) = tokio_tungstenite::connect_async(url)
+            .await
+            .context("err connecting to real-time transcription endpoint")?;
+        let (mut write, mut read) = ws_stream.split();
+
+        // Keep the mic input stream alive for the duration of the session; it
+        // stops producing callbacks (and gets torn down) once dropped.
+        let (mut audio_rx, mut mic_stream) = match source {
+            AudioSource::File(path) => (spawn_file_feed(path).await?, None),
+            AudioSource::Microphone => {
+                let (rx, mic_stream) = spawn_microphone_feed()?;
+                (rx, Some(mic_stream))
+            }
+        };
+
+        let mut terminated_sent = false;
+        let mut finals = Vec::new();
+
+        // Drive sending audio frames and reading transcript events concurrently, so
+        // partials/finals the server emits mid-upload are printed immediately
+        // instead of bursting out after the whole file has been sent. The mic feed
+        // never closes on its own, so also watch for ctrl-c to finalize the session
+        // and let the user get their finals written out instead of just killing it.
+        loop {
+            tokio::select! {
+                frame = audio_rx.recv(), if !terminated_sent => {
+                    match frame {
+                        Some(chunk) => {
+                            let frame = AudioFrame {
+                                audio_data: &STANDARD.encode(chunk),
+                            };
+                            write
+                                .send(Message::Text(serde_json::to_string(&frame)?))
+                                .await?;
+                        }
+                        None => {
+                            write
+                                .send(Message::Text(serde_json::to_string(&TerminateSession {
+                                    terminate_session: true,
+                                })?))
+                                .await?;
+                            terminated_sent = true;
+                        }
+                    }
+                }
+                result = tokio::signal::ctrl_c(), if !terminated_sent => {
+                    result.context("err listening for ctrl-c")?;
+                    // Drop the mic stream so it stops capturing, then tell the
+                    // server we're done; the read branch below keeps running so
+                    // any finals the server still emits get collected before exit.
+                    mic_stream.take();
+                    write
+                        .send(Message::Text(serde_json::to_string(&TerminateSession {
+                            terminate_session: true,
+                        })?))
+                        .await?;
+                    terminated_sent = true;
+                }
+                msg = read.next() => {
+                    match msg {
+                        Some(Ok(Message::Text(text))) => {
+                            if self.handle_event(&text, &mut finals)? {
+                                break;
+                            }
+                        }
+                        Some(Ok(Message::Ping(payload))) => write.send(Message::Pong(payload)).await?,
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => return Err(anyhow!(e)),
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        Ok(finals)
+    }
+
+    /// Parses and prints a single `StreamEvent`. Returns `Ok(true)` once the
+    /// session has been terminated by the server.
+    fn handle_event(&self, text: &str, finals: &mut Vec<String>) -> Result<bool> {
+        let event: StreamEvent =
+            serde_json::from_str(text).context("could not parse stream event")?;
+        match event {
+            StreamEvent::SessionBegins { session_id } => {
+                println!("session started: {}", session_id);
+            }
+            StreamEvent::PartialTranscript { text } => {
+                print!("\r{}", text);
+                io::stdout().flush()?;
+            }
+            StreamEvent::FinalTranscript {
+                text,
+                audio_start,
+                audio_end,
+            } => {
+                println!("\r[{}ms-{}ms] {}", audio_start, audio_end, text);
+                finals.push(text);
+            }
+            StreamEvent::Error { error } => return Err(anyhow!(error)),
+            StreamEvent::SessionTerminated => return Ok(true),
+        }
+        Ok(false)
+    }
+}
+
+/// Reads `path` and feeds it to the returned channel in ~100ms chunks, matching
+/// the pace real-time audio would arrive at. The channel closes once the whole
+/// file has been sent.
+async fn spawn_file_feed(path: std::path::PathBuf) -> Result<mpsc::UnboundedReceiver<Vec<u8>>> {
+    let audio = fs::read(&path)
+        .await
+        .with_context(|| format!("failed to read audio file {:#?}", path))?;
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        let mut tick = tokio::time::interval(Duration::from_millis(100));
+        for chunk in audio.chunks(CHUNK_BYTES) {
+            tick.tick().await;
+            if tx.send(chunk.to_vec()).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(rx)
+}
+
+/// Opens the default microphone input device and feeds captured PCM frames to
+/// the returned channel as they're recorded. The returned `cpal::Stream` must
+/// be kept alive for the duration of the capture; dropping it stops the mic.
+fn spawn_microphone_feed() -> Result<(mpsc::UnboundedReceiver<Vec<u8>>, cpal::Stream)> {
+    let host = cpal::default_host();
+    let device = host
+        .default_input_device()
+        .ok_or_else(|| anyhow!("no default microphone input device available"))?;
+
+    // Request 16kHz mono 16-bit PCM directly so frames can be forwarded as-is;
+    // not all devices support this natively, but it's the common case on laptops.
+    let config = cpal::StreamConfig {
+        channels: 1,
+        sample_rate: cpal::SampleRate(SAMPLE_RATE),
+        buffer_size: cpal::BufferSize::Default,
+    };
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    let stream = device
+        .build_input_stream(
+            &config,
+            move |samples: &[i16], _| {
+                let mut bytes = Vec::with_capacity(samples.len() * 2);
+                for sample in samples {
+                    bytes.extend_from_slice(&sample.to_le_bytes());
+                }
+                let _ = tx.send(bytes);
+            },
+            |err| eprintln!("microphone input stream error: {}", err),
+            None,
+        )
+        .context("failed to build microphone input stream")?;
+    stream.play().context("failed to start microphone capture")?;
+
+    Ok((rx, stream))
+}
+
+/// Runs the real-time streaming transcription process.
+pub async fn run(config: &Config, args: StreamArgs) -> Result<()> {
+    let (source, id) = match (args.audio_file, args.mic) {
+        (Some(path), false) => {
+            let id = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("stream")
+                .to_string();
+            (AudioSource::File(path), id)
+        }
+        (None, true) => (AudioSource::Microphone, "mic".to_string()),
+        (Some(_), true) => return Err(anyhow!("--audio-file and --mic are mutually exclusive")),
+        (None, false) => return Err(anyhow!("either --audio-file or --mic is required")),
+    };
+
+    let stream_url = config
+        .stream_url
+        .clone()
+        .unwrap_or_else(|| DEFAULT_STREAM_URL.to_string());
+
+    let streamer = Streamer::new(&config.token, &stream_url);
+    let finals = streamer.stream(source).await?;
+    write_to_file(&id, &finals)
+}
+
+/// Writes the committed final transcripts to a `{id}.json` file.
+fn write_to_file(id: &str, finals: &[String]) -> Result<()> {
+    let file_name = format!("{}.json", id);
+    let pretty_json = serde_json::to_string_pretty(finals)?;
+    std::fs::write(std::env::current_dir()?.join(file_name), pretty_json)?;
+    Ok(())
+}
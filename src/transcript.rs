@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The lifecycle state of a submitted transcription job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Queued,
+    Processing,
+    Completed,
+    Error,
+}
+
+/// A single transcribed word with its timing and confidence.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Word {
+    pub text: String,
+    pub start: u64,
+    pub end: u64,
+    pub confidence: f64,
+    pub speaker: Option<String>,
+}
+
+/// A named entity detected in the transcript (e.g. a person, location, or organization).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Entity {
+    pub entity_type: String,
+    pub text: String,
+    pub start: u64,
+    pub end: u64,
+}
+
+/// IAB topic classification results, keyed by label with a relevance score.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct IabCategories {
+    #[serde(default)]
+    pub summary: HashMap<String, f64>,
+}
+
+impl IabCategories {
+    /// Returns the `n` highest-relevance topic labels, most relevant first.
+    pub fn top_labels(&self, n: usize) -> Vec<(&str, f64)> {
+        let mut labels: Vec<(&str, f64)> = self
+            .summary
+            .iter()
+            .map(|(label, score)| (label.as_str(), *score))
+            .collect();
+        labels.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        labels.truncate(n);
+        labels
+    }
+}
+
+/// The full transcript resource returned by the transcription service.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TranscriptResponse {
+    pub id: String,
+    pub status: Status,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub words: Vec<Word>,
+    pub iab_categories_result: Option<IabCategories>,
+    pub entities: Option<Vec<Entity>>,
+    pub error: Option<String>,
+}
+
+impl TranscriptResponse {
+    /// Prints a structured summary of detected entities and top IAB topics.
+    pub fn print_summary(&self) {
+        if let Some(entities) = &self.entities {
+            let mut by_type: HashMap<&str, Vec<&str>> = HashMap::new();
+            for entity in entities {
+                by_type
+                    .entry(entity.entity_type.as_str())
+                    .or_default()
+                    .push(entity.text.as_str());
+            }
+            println!("Entities:");
+            for (entity_type, texts) in by_type {
+                println!("  {}: {}", entity_type, texts.join(", "));
+            }
+        }
+
+        if let Some(iab) = &self.iab_categories_result {
+            println!("Top topics:");
+            for (label, score) in iab.top_labels(5) {
+                println!("  {} ({:.2})", label, score);
+            }
+        }
+    }
+}